@@ -3,7 +3,8 @@ use nu_parser::{parse_unit_value, DURATION_UNIT_GROUPS};
 use nu_protocol::{
     ast::{Call, CellPath, Expr},
     engine::{Command, EngineState, Stack},
-    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Unit, Value,
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Unit,
+    Value,
 };
 
 const NS_PER_SEC: i64 = 1_000_000_000;
@@ -18,13 +19,19 @@ impl Command for SubCommand {
     fn signature(&self) -> Signature {
         Signature::build("into duration")
             .input_output_types(vec![
+                (Type::Int, Type::Duration),
                 (Type::String, Type::Duration),
                 (Type::Duration, Type::Duration),
                 (Type::Table(vec![]), Type::Table(vec![])),
-                //todo: record<hour,minute,sign> | into duration -> Duration
-                //(Type::Record(vec![]), Type::Record(vec![])),
+                (Type::Record(vec![]), Type::Duration),
             ])
             //.allow_variants_without_examples(true)
+            .named(
+                "unit",
+                SyntaxShape::String,
+                "Unit to convert number into (will have an effect only with integer input)",
+                Some('u'),
+            )
             .rest(
                 "rest",
                 SyntaxShape::CellPath,
@@ -132,6 +139,30 @@ impl Command for SubCommand {
                     span,
                 }),
             },
+            Example {
+                description: "Convert a number of seconds to duration",
+                example: "1_000 | into duration --unit sec",
+                result: Some(Value::Duration {
+                    val: 1_000 * NS_PER_SEC,
+                    span,
+                }),
+            },
+            Example {
+                description: "Convert an ISO 8601 duration string to duration",
+                example: "'P1DT2H' | into duration",
+                result: Some(Value::Duration {
+                    val: (24 + 2) * 60 * 60 * NS_PER_SEC,
+                    span,
+                }),
+            },
+            Example {
+                description: "Convert a record of time components to duration",
+                example: "{day: 10, hour: 2, minute: 6, second: 50, sign: '+'} | into duration",
+                result: Some(Value::Duration {
+                    val: (((((10 * 24) + 2) * 60) + 6) * 60 + 50) * NS_PER_SEC,
+                    span,
+                }),
+            },
         ]
     }
 }
@@ -147,16 +178,21 @@ fn into_duration(
         None => call.head,
     };
     let column_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+    let unit: Option<Spanned<String>> = call.get_flag(engine_state, stack, "unit")?;
 
     input.map(
         move |v| {
             if column_paths.is_empty() {
-                action(&v, span)
+                action(&v, unit.as_ref(), span)
             } else {
+                let unit = unit.clone();
                 let mut ret = v;
                 for path in &column_paths {
-                    let r =
-                        ret.update_cell_path(&path.members, Box::new(move |old| action(old, span)));
+                    let unit = unit.clone();
+                    let r = ret.update_cell_path(
+                        &path.members,
+                        Box::new(move |old| action(old, unit.as_ref(), span)),
+                    );
                     if let Err(error) = r {
                         return Value::Error {
                             error: Box::new(error),
@@ -185,18 +221,230 @@ fn split_whitespace_indices(s: &str, span: Span) -> impl Iterator<Item = (&str,
     })
 }
 
+// Nanoseconds in a single unit of the given name, or `None` if the name is not a known unit.
+fn unit_to_ns_factor(unit: &str) -> Option<i64> {
+    Some(match unit {
+        "ns" => 1,
+        "us" | "\u{00B5}s" | "\u{03BC}s" => 1000,
+        "ms" => 1000 * 1000,
+        "sec" => NS_PER_SEC,
+        "min" => NS_PER_SEC * 60,
+        "hr" => NS_PER_SEC * 60 * 60,
+        "day" => NS_PER_SEC * 60 * 60 * 24,
+        "wk" => NS_PER_SEC * 60 * 60 * 24 * 7,
+        _ => return None,
+    })
+}
+
+// Nanoseconds in a single unit named by a record field, or `None` if the name is not a known
+// time component. These are the long-form names that `into record` emits when it decomposes a
+// duration, so the two commands round-trip.
+fn record_field_to_ns_factor(field: &str) -> Option<i64> {
+    Some(match field {
+        "nanosecond" => 1,
+        "microsecond" => 1000,
+        "millisecond" => 1000 * 1000,
+        "second" => NS_PER_SEC,
+        "minute" => NS_PER_SEC * 60,
+        "hour" => NS_PER_SEC * 60 * 60,
+        "day" => NS_PER_SEC * 60 * 60 * 24,
+        "week" => NS_PER_SEC * 60 * 60 * 24 * 7,
+        _ => return None,
+    })
+}
+
+fn record_to_duration(
+    cols: &[String],
+    vals: &[Value],
+    span: Span,
+    head_span: Span,
+) -> Result<i64, ShellError> {
+    let mut duration_ns: i64 = 0;
+    let mut sign: i64 = 1;
+
+    for (col, val) in cols.iter().zip(vals) {
+        if col == "sign" {
+            match val.as_string()?.as_str() {
+                "+" => sign = 1,
+                "-" => sign = -1,
+                other => {
+                    return Err(ShellError::CantConvertToDuration {
+                        details: other.to_string(),
+                        dst_span: head_span,
+                        src_span: val.expect_span(),
+                        help: Some("the 'sign' field must be \"+\" or \"-\"".to_string()),
+                    })
+                }
+            }
+            continue;
+        }
+
+        let factor = match record_field_to_ns_factor(col) {
+            Some(factor) => factor,
+            None => {
+                return Err(ShellError::CantConvertToDuration {
+                    details: col.clone(),
+                    dst_span: head_span,
+                    src_span: span,
+                    help: Some(
+                        "supported fields are week, day, hour, minute, second, millisecond, \
+                         microsecond, nanosecond, and sign"
+                            .to_string(),
+                    ),
+                })
+            }
+        };
+
+        let field_ns = val
+            .as_i64()?
+            .checked_mul(factor)
+            .ok_or_else(|| duration_overflow(col, span))?;
+        duration_ns = duration_ns
+            .checked_add(field_ns)
+            .ok_or_else(|| duration_overflow(col, span))?;
+    }
+
+    if sign < 0 {
+        duration_ns
+            .checked_neg()
+            .ok_or_else(|| duration_overflow("sign", span))
+    } else {
+        Ok(duration_ns)
+    }
+}
+
+// Build the "value too large" error anchored at the token that triggered the overflow.
+fn duration_overflow(detail: &str, span: Span) -> ShellError {
+    ShellError::CantConvertToDuration {
+        details: detail.to_string(),
+        dst_span: span,
+        src_span: span,
+        help: Some(
+            "duration value is too large to represent; max duration is i64::MAX nanoseconds"
+                .to_string(),
+        ),
+    }
+}
+
+// Convert `value * factor` nanoseconds into a checked i64, erroring on overflow (including the
+// float-to-int saturating cast, which would otherwise silently clamp to i64::MAX/MIN).
+fn float_to_checked_ns(value: f64, factor: i64, detail: &str, span: Span) -> Result<i64, ShellError> {
+    let rounded = (value * factor as f64).round();
+    if !rounded.is_finite()
+        || rounded < i64::MIN as f64
+        || rounded >= 9_223_372_036_854_775_808.0 // 2^63, i.e. i64::MAX + 1
+    {
+        return Err(duration_overflow(detail, span));
+    }
+    Ok(rounded as i64)
+}
+
 fn compound_to_duration(s: &str, span: Span) -> Result<i64, ShellError> {
     let mut duration_ns: i64 = 0;
 
     for (substring, substring_span) in split_whitespace_indices(s, span) {
         let sub_ns = string_to_duration(substring, substring_span)?;
-        duration_ns += sub_ns;
+        duration_ns = duration_ns
+            .checked_add(sub_ns)
+            .ok_or_else(|| duration_overflow(substring, substring_span))?;
+    }
+
+    Ok(duration_ns)
+}
+
+// Parse an ISO 8601 / XSD duration string such as `P1DT2H3M4S` or `PT0.5S`.
+//
+// The grammar is `PnWnYnMnDTnHnMnS`: the portion before the `T` carries date designators
+// (`W`, `Y`, `M`, `D`) and the portion after carries time designators (`H`, `M`, `S`). A
+// fractional seconds component is accepted. Because the crate's largest unit is the week and the
+// nanosecond base cannot represent calendar months or years unambiguously, the `Y` and date-part
+// `M` designators are rejected with an explanatory error.
+fn iso8601_to_duration(s: &str, span: Span) -> Result<i64, ShellError> {
+    let cant_convert = |detail: &str, help: &str| ShellError::CantConvertToDuration {
+        details: detail.to_string(),
+        dst_span: span,
+        src_span: span,
+        help: Some(help.to_string()),
+    };
+
+    let (date_part, time_part) = match s[1..].split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (&s[1..], None),
+    };
+
+    // Scan a run of `<number><designator>` pairs; the number may carry a fractional component.
+    fn scan(part: &str) -> Result<Vec<(f64, char)>, char> {
+        let mut pairs = Vec::new();
+        let mut number = String::new();
+        for c in part.chars() {
+            if c.is_ascii_digit() || c == '.' {
+                number.push(c);
+            } else {
+                let value = number.parse::<f64>().map_err(|_| c)?;
+                number.clear();
+                pairs.push((value, c));
+            }
+        }
+        if !number.is_empty() {
+            return Err('?');
+        }
+        Ok(pairs)
+    }
+
+    let mut duration_ns: i64 = 0;
+    let mut consumed = false;
+    let mut accumulate = |value: f64, factor: i64| -> Result<(), ShellError> {
+        let ns = float_to_checked_ns(value, factor, s, span)?;
+        duration_ns = duration_ns
+            .checked_add(ns)
+            .ok_or_else(|| duration_overflow(s, span))?;
+        consumed = true;
+        Ok(())
+    };
+
+    for (value, designator) in
+        scan(date_part).map_err(|_| cant_convert(s, "invalid ISO 8601 duration string"))?
+    {
+        match designator {
+            'W' => accumulate(value, NS_PER_SEC * 60 * 60 * 24 * 7)?,
+            'D' => accumulate(value, NS_PER_SEC * 60 * 60 * 24)?,
+            'Y' | 'M' => {
+                return Err(cant_convert(
+                    &designator.to_string(),
+                    "calendar years and months are not supported; use weeks, days, or smaller units",
+                ))
+            }
+            other => return Err(cant_convert(&other.to_string(), "unknown ISO 8601 designator")),
+        }
+    }
+
+    if let Some(time_part) = time_part {
+        for (value, designator) in
+            scan(time_part).map_err(|_| cant_convert(s, "invalid ISO 8601 duration string"))?
+        {
+            match designator {
+                'H' => accumulate(value, NS_PER_SEC * 60 * 60)?,
+                'M' => accumulate(value, NS_PER_SEC * 60)?,
+                'S' => accumulate(value, NS_PER_SEC)?,
+                other => {
+                    return Err(cant_convert(&other.to_string(), "unknown ISO 8601 designator"))
+                }
+            }
+        }
+    }
+
+    if !consumed {
+        return Err(cant_convert(s, "invalid ISO 8601 duration string"));
     }
 
     Ok(duration_ns)
 }
 
 fn string_to_duration(s: &str, span: Span) -> Result<i64, ShellError> {
+    if s.starts_with('P') {
+        return iso8601_to_duration(s, span);
+    }
+
     if let Some(Ok(expression)) = parse_unit_value(
         s.as_bytes(),
         span,
@@ -205,16 +453,25 @@ fn string_to_duration(s: &str, span: Span) -> Result<i64, ShellError> {
         |x| x,
     ) {
         if let Expr::ValueWithUnit(value, unit) = expression.expr {
-            if let Expr::Int(x) = value.expr {
-                match unit.item {
-                    Unit::Nanosecond => return Ok(x),
-                    Unit::Microsecond => return Ok(x * 1000),
-                    Unit::Millisecond => return Ok(x * 1000 * 1000),
-                    Unit::Second => return Ok(x * NS_PER_SEC),
-                    Unit::Minute => return Ok(x * 60 * NS_PER_SEC),
-                    Unit::Hour => return Ok(x * 60 * 60 * NS_PER_SEC),
-                    Unit::Day => return Ok(x * 24 * 60 * 60 * NS_PER_SEC),
-                    Unit::Week => return Ok(x * 7 * 24 * 60 * 60 * NS_PER_SEC),
+            let factor = match unit.item {
+                Unit::Nanosecond => Some(1),
+                Unit::Microsecond => Some(1000),
+                Unit::Millisecond => Some(1000 * 1000),
+                Unit::Second => Some(NS_PER_SEC),
+                Unit::Minute => Some(60 * NS_PER_SEC),
+                Unit::Hour => Some(60 * 60 * NS_PER_SEC),
+                Unit::Day => Some(24 * 60 * 60 * NS_PER_SEC),
+                Unit::Week => Some(7 * 24 * 60 * 60 * NS_PER_SEC),
+                _ => None,
+            };
+            if let Some(factor) = factor {
+                match value.expr {
+                    Expr::Int(x) => {
+                        return x
+                            .checked_mul(factor)
+                            .ok_or_else(|| duration_overflow(s, span))
+                    }
+                    Expr::Float(f) => return float_to_checked_ns(f, factor, s, span),
                     _ => {}
                 }
             }
@@ -229,9 +486,40 @@ fn string_to_duration(s: &str, span: Span) -> Result<i64, ShellError> {
     })
 }
 
-fn action(input: &Value, span: Span) -> Value {
+fn action(input: &Value, unit: Option<&Spanned<String>>, span: Span) -> Value {
     match input {
         Value::Duration { .. } => input.clone(),
+        Value::Int { val, .. } => {
+            let factor = match unit {
+                Some(unit) => match unit_to_ns_factor(&unit.item) {
+                    Some(factor) => factor,
+                    None => {
+                        return Value::Error {
+                            error: Box::new(ShellError::CantConvertToDuration {
+                                details: unit.item.clone(),
+                                dst_span: span,
+                                src_span: unit.span,
+                                help: Some(
+                                    "supported units are ns, us/µs, ms, sec, min, hr, day, and wk"
+                                        .to_string(),
+                                ),
+                            }),
+                        }
+                    }
+                },
+                // no unit given: treat the integer as a raw nanosecond count
+                None => 1,
+            };
+            match val.checked_mul(factor) {
+                Some(val) => Value::Duration { val, span },
+                None => Value::Error {
+                    error: Box::new(duration_overflow(
+                        &val.to_string(),
+                        unit.map(|u| u.span).unwrap_or(span),
+                    )),
+                },
+            }
+        }
         Value::String {
             val,
             span: value_span,
@@ -241,11 +529,21 @@ fn action(input: &Value, span: Span) -> Value {
                 error: Box::new(error),
             },
         },
+        Value::Record {
+            cols,
+            vals,
+            span: value_span,
+        } => match record_to_duration(cols, vals, *value_span, span) {
+            Ok(val) => Value::Duration { val, span },
+            Err(error) => Value::Error {
+                error: Box::new(error),
+            },
+        },
         // Propagate errors by explicitly matching them before the final case.
         Value::Error { .. } => input.clone(),
         other => Value::Error {
             error: Box::new(ShellError::OnlySupportsThisInputType {
-                exp_input_type: "string or duration".into(),
+                exp_input_type: "string, duration, int or record".into(),
                 wrong_type: other.get_type().to_string(),
                 dst_span: span,
                 src_span: other.expect_span(),
@@ -281,9 +579,15 @@ mod test {
     #[case("3wk", 3 * 7 * 24 * 60 * 60 * NS_PER_SEC)]
     #[case("86hr 26ns", 86 * 3600 * NS_PER_SEC + 26)] // compound duration string
     #[case("14ns 3hr 17sec", 14 + 3 * 3600 * NS_PER_SEC + 17 * NS_PER_SEC)] // compound string with units in random order
+    #[case("P1DT2H", 24 * 3600 * NS_PER_SEC + 2 * 3600 * NS_PER_SEC)] // ISO 8601 date + time
+    #[case("PT30M", 30 * 60 * NS_PER_SEC)] // ISO 8601 time-only
+    #[case("P2W", 2 * 7 * 24 * 3600 * NS_PER_SEC)] // ISO 8601 weeks
+    #[case("PT0.5S", NS_PER_SEC / 2)] // ISO 8601 fractional seconds
+    #[case("1.5hr", 90 * 60 * NS_PER_SEC)] // fractional unit quantity
+    #[case("0.5sec", 500 * 1000 * 1000)] // fractional seconds -> 500ms
 
     fn turns_string_to_duration(#[case] phrase: &str, #[case] expected_duration_val: i64) {
-        let actual = action(&Value::test_string(phrase), Span::new(0, phrase.len()));
+        let actual = action(&Value::test_string(phrase), None, Span::new(0, phrase.len()));
         match actual {
             Value::Duration {
                 val: observed_val, ..
@@ -295,4 +599,85 @@ mod test {
             }
         }
     }
+
+    #[rstest]
+    #[case(1_234, None, 1_234)] // bare integer is a raw nanosecond count
+    #[case(1_234, Some("ns"), 1_234)]
+    #[case(1_234, Some("us"), 1_234 * 1000)]
+    #[case(1_000, Some("sec"), 1_000 * NS_PER_SEC)]
+    #[case(2, Some("wk"), 2 * 7 * 24 * 60 * 60 * NS_PER_SEC)]
+    fn turns_int_to_duration(
+        #[case] phrase: i64,
+        #[case] unit: Option<&str>,
+        #[case] expected_duration_val: i64,
+    ) {
+        let unit = unit.map(|u| Spanned {
+            item: u.to_string(),
+            span: Span::test_data(),
+        });
+        let actual = action(&Value::test_int(phrase), unit.as_ref(), Span::test_data());
+        match actual {
+            Value::Duration {
+                val: observed_val, ..
+            } => {
+                assert_eq!(expected_duration_val, observed_val, "expected != observed")
+            }
+            other => {
+                panic!("Expected Value::Duration, observed {other:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn turns_record_to_duration() {
+        let span = Span::test_data();
+        let input = Value::Record {
+            cols: vec![
+                "day".to_string(),
+                "hour".to_string(),
+                "sign".to_string(),
+            ],
+            vals: vec![
+                Value::test_int(1),
+                Value::test_int(2),
+                Value::test_string("-"),
+            ],
+            span,
+        };
+        let expected = -((1 * 24 + 2) * 60 * 60 * NS_PER_SEC);
+        match action(&input, None, span) {
+            Value::Duration { val, .. } => assert_eq!(expected, val),
+            other => panic!("Expected Value::Duration, observed {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case("P")]
+    #[case("PT")]
+    fn empty_iso8601_string_errors(#[case] phrase: &str) {
+        assert!(matches!(
+            action(&Value::test_string(phrase), None, Span::new(0, phrase.len())),
+            Value::Error { .. }
+        ));
+    }
+
+    #[test]
+    fn overflowing_duration_string_errors() {
+        let phrase = "99999999wk";
+        assert!(matches!(
+            action(&Value::test_string(phrase), None, Span::new(0, phrase.len())),
+            Value::Error { .. }
+        ));
+    }
+
+    #[test]
+    fn unknown_record_field_errors() {
+        let span = Span::test_data();
+        let input = Value::Record {
+            cols: vec!["fortnight".to_string()],
+            vals: vec![Value::test_int(1)],
+            span,
+        };
+        assert!(matches!(action(&input, None, span), Value::Error { .. }));
+    }
 }